@@ -7,6 +7,12 @@
  * @scope
  * - Defines the `check_shot` confidential instruction for hit/miss detection.
  * - Defines the `reveal_boards` confidential instruction for end-of-game verification.
+ * - Defines the `validate_board` confidential instruction for fleet-legality
+ *   enforcement at board submission time.
+ * - Defines the `audit_game` confidential instruction, which replays the
+ *   public shot log against both revealed boards at end of game.
+ * - Defines the `coin_flip` confidential instruction, a common coin used to
+ *   fairly decide which player takes the first turn.
  * - Specifies the data structures for encrypted inputs and public outputs.
  *
  * @dependencies
@@ -31,6 +37,23 @@
  mod circuits {
      use arcis_imports::*;
 
+     /**
+      * @description
+      * The exact number of occupied cells a legal fleet must cover, summed
+      * across the classic Battleship ship sizes (5 + 4 + 3 + 3 + 2).
+      */
+     pub const SHIP_CELL_COUNT: u8 = 17;
+
+     /**
+      * @description
+      * The maximum number of shots `audit_game` replays. Mirrors
+      * `MAX_SHOTS_LOGGED` in the on-chain program: each player's `targeted`
+      * bitmask caps them at 64 shots against an 8x8 board, so 128 covers the
+      * true worst case of both players exhausting their board before either
+      * wins.
+      */
+     pub const MAX_SHOTS_LOGGED: usize = 128;
+
      //================================================================
      // CHECK SHOT CIRCUIT
      //================================================================
@@ -126,10 +149,171 @@
      ) -> RevealedBoards {
          let p1_board_secret = p1_board_ctxt.to_arcis();
          let p2_board_secret = p2_board_ctxt.to_arcis();
- 
+
          RevealedBoards {
              p1_board: p1_board_secret.board.reveal(),
              p2_board: p2_board_secret.board.reveal(),
          }
      }
+
+     //================================================================
+     // VALIDATE BOARD CIRCUIT
+     //================================================================
+
+     /**
+      * @description
+      * Confidentially enforces fleet legality before a submitted board is
+      * accepted into play. A board is legal only if every cell is a `0`/`1`
+      * occupancy flag and exactly `SHIP_CELL_COUNT` cells are occupied, which
+      * rules out both an all-ships board and an empty board.
+      *
+      * This circuit is data-independent by construction: it walks all 64
+      * cells unconditionally and never branches on a secret value, as
+      * required by Arcis.
+      *
+      * @inputs
+      * - `board_ctxt: Enc<Shared, FullBoard>`: The player's encrypted board,
+      *   submitted alongside `submit_board`.
+      * - `player_index: u8`: The public index (0 or 1) of the submitting
+      *   player. Not secret; echoed back in the output so the callback can
+      *   tell which player's submission this result belongs to, since two
+      *   `validate_board` computations can be in flight at once.
+      *
+      * @returns
+      * - `(u8, u8)`: `(is_legal, player_index)`. `is_legal` is `1` if the
+      *   board is a legal fleet layout, `0` otherwise; `player_index` is
+      *   passed through unchanged from the input.
+      */
+     #[instruction]
+     pub fn validate_board(board_ctxt: Enc<Shared, FullBoard>, player_index: u8) -> (u8, u8) {
+         let board = board_ctxt.to_arcis();
+
+         let mut sum: u8 = 0;
+         let mut ok: bool = true;
+         for y in 0..8 {
+             for x in 0..8 {
+                 let cell = board.board[y][x];
+                 sum += cell;
+                 ok &= cell * (cell.wrapping_sub(1)) == 0;
+             }
+         }
+         ok &= sum == SHIP_CELL_COUNT;
+
+         (ok.reveal() as u8, player_index)
+     }
+
+     //================================================================
+     // AUDIT GAME CIRCUIT
+     //================================================================
+
+     /**
+      * @description
+      * A single publicly-recorded shot from `Game.game_log`, replayed against
+      * the revealed boards at the end of a game.
+      *
+      * @fields
+      * - `x`, `y`: The targeted coordinate.
+      * - `defender`: `0` if Player 1's board was targeted, `1` if Player 2's
+      *   board was targeted.
+      * - `result`: The hit/miss result that was publicly recorded on-chain
+      *   for this shot (`1` for hit, `0` for miss).
+      */
+     pub struct PublicShot {
+         pub x: u8,
+         pub y: u8,
+         pub defender: u8,
+         pub result: u8,
+     }
+
+     /**
+      * @description
+      * End-of-game integrity audit. Replays the first `log_len` entries of the
+      * public shot log against both revealed boards and flags, per player,
+      * whether any of their recorded shot results are inconsistent with their
+      * opponent's true board. This binds the public log to the private boards,
+      * the same way a commitment check binds a public claim to the secret it
+      * was derived from.
+      *
+      * Iterates all `MAX_SHOTS_LOGGED` log slots unconditionally and masks
+      * out slots at or past `log_len` with the public `active` flag, so
+      * execution never branches on a secret value. `MAX_SHOTS_LOGGED` is 128
+      * because each player's `targeted` bitmask on-chain caps them at 64
+      * shots against an 8x8 board, so 128 covers the true worst case of both
+      * players exhausting their board before either wins.
+      *
+      * @inputs
+      * - `p1: Enc<Shared, FullBoard>`: Player 1's encrypted board.
+      * - `p2: Enc<Shared, FullBoard>`: Player 2's encrypted board.
+      * - `log: [PublicShot; MAX_SHOTS_LOGGED]`: The public shot log, padded
+      *   to `MAX_SHOTS_LOGGED` entries.
+      * - `log_len: u8`: The number of entries in `log` that are actually live.
+      *
+      * @returns
+      * - `(u8, u8)`: `(p1_cheated, p2_cheated)`, each `1` if that player's
+      *   shots against their opponent's board don't match the true board.
+      */
+     #[instruction]
+     pub fn audit_game(
+         p1: Enc<Shared, FullBoard>,
+         p2: Enc<Shared, FullBoard>,
+         log: [PublicShot; MAX_SHOTS_LOGGED],
+         log_len: u8,
+     ) -> (u8, u8) {
+         let p1_board = p1.to_arcis();
+         let p2_board = p2.to_arcis();
+
+         let mut p1_cheated: u8 = 0;
+         let mut p2_cheated: u8 = 0;
+
+         for i in 0..MAX_SHOTS_LOGGED {
+             let shot = log[i];
+             let active: u8 = if (i as u8) < log_len { 1 } else { 0 };
+
+             let true_p1_cell = p1_board.board[shot.y as usize][shot.x as usize];
+             let true_p2_cell = p2_board.board[shot.y as usize][shot.x as usize];
+
+             // `defender` is public, so selecting between the two secret cells
+             // by it is a public-index select, not a branch on a secret.
+             let true_cell = true_p1_cell * (1 - shot.defender) + true_p2_cell * shot.defender;
+             let mismatch = (true_cell ^ shot.result) * active;
+
+             // `defender == 1` means Player 1 fired this shot, so a mismatch
+             // implicates Player 1; `defender == 0` implicates Player 2.
+             p1_cheated |= mismatch * shot.defender;
+             p2_cheated |= mismatch * (1 - shot.defender);
+         }
+
+         (p1_cheated.reveal(), p2_cheated.reveal())
+     }
+
+     //================================================================
+     // COIN FLIP CIRCUIT
+     //================================================================
+
+     /**
+      * @description
+      * A fair "common coin" used to pick who takes the first turn. Each
+      * player contributes a secret random seed they commit to before either
+      * side has seen the other's seed (via `submit_board`), so neither player
+      * can bias the result. The two seeds are XORed inside MPC and only the
+      * resulting least-significant bit is revealed, leaving both seeds
+      * otherwise secret.
+      *
+      * @inputs
+      * - `s1: Enc<Shared, u64>`: Player 1's secret seed.
+      * - `s2: Enc<Shared, u64>`: Player 2's secret seed.
+      *
+      * @returns
+      * - `u8`: `0` if Player 1 takes the first turn, `1` if Player 2 does.
+      */
+     #[instruction]
+     pub fn coin_flip(s1: Enc<Shared, u64>, s2: Enc<Shared, u64>) -> u8 {
+         let seed1 = s1.to_arcis();
+         let seed2 = s2.to_arcis();
+
+         let combined = seed1 ^ seed2;
+         let starter_bit = (combined & 1) as u8;
+
+         starter_bit.reveal()
+     }
  }
\ No newline at end of file
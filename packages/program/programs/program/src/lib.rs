@@ -28,15 +28,30 @@ use arcium_anchor::prelude::*;
 
 // Arcium v2 specific types (will be implemented as needed)
 // use arcium_anchor::types::{CircuitSource, CallbackAccount, MXEAccount, FeePool};
- 
+
  // Program ID for the deployed Cipher Stratego program on devnet
  declare_id!("G5gFnuGRrLE4eXcZMvY5Fppm9Mis34AtXCo7SsvCdtZm");
- 
+
  // Constants for the Arcis computation definition offsets.
  // These are used to uniquely identify our confidential instructions.
  // TODO: Re-enable when Arcium circuits are built
- // const COMP_DEF_OFFSET_CHECK_SHOT: u32 = comp_def_offset("check_shot");
  // const COMP_DEF_OFFSET_REVEAL_BOARDS: u32 = comp_def_offset("reveal_boards");
+ const COMP_DEF_OFFSET_CHECK_SHOT: u32 = comp_def_offset("check_shot");
+ const COMP_DEF_OFFSET_VALIDATE_BOARD: u32 = comp_def_offset("validate_board");
+ const COMP_DEF_OFFSET_AUDIT_GAME: u32 = comp_def_offset("audit_game");
+ const COMP_DEF_OFFSET_COIN_FLIP: u32 = comp_def_offset("coin_flip");
+
+ // The number of occupied cells a legal fleet must cover (5 + 4 + 3 + 3 + 2),
+ // mirroring `SHIP_CELL_COUNT` in the `validate_board` Arcis circuit. A
+ // player wins once their hit count reaches this many.
+ const SHIP_CELL_COUNT: u8 = 17;
+
+ // The maximum number of shots `game_log` can hold. Each player's `targeted`
+ // bitmask caps them at 64 shots against an 8x8 board, so 128 covers the
+ // true worst case of both players exhausting their board before either
+ // reaches `SHIP_CELL_COUNT` hits. Mirrors `MAX_SHOTS_LOGGED` in the
+ // `audit_game` Arcis circuit, which replays exactly this many log slots.
+ const MAX_SHOTS_LOGGED: usize = 128;
  
  /**
   * @description
@@ -55,17 +70,29 @@ use arcium_anchor::prelude::*;
      /**
       * @description Initializes a new game.
       * Creates the `Game` PDA and sets the caller as Player 1.
+      *
+      * @validation
+      * - Fails if `turn_timeout_secs` doesn't fit in `i64`; `claim_timeout_win`
+      *   casts it to `i64` to compare against `Clock::unix_timestamp`, and an
+      *   unchecked value above `i64::MAX` would wrap negative there, making
+      *   the timeout appear to have already elapsed.
       */
-         pub fn initialize_game(ctx: Context<InitializeGame>, game_seed: u64) -> Result<()> {
+         pub fn initialize_game(ctx: Context<InitializeGame>, game_seed: u64, turn_timeout_secs: u64) -> Result<()> {
         let game = &mut ctx.accounts.game;
         msg!("Initializing game with seed: {}", game_seed);
 
+        require!(turn_timeout_secs <= i64::MAX as u64, GameError::InvalidTurnTimeout);
+
         // Set initial game properties
         game.players[0] = ctx.accounts.payer.key();
         game.players[1] = Pubkey::default(); // Player 2 is not yet present
         game.game_state = GameState::AwaitingPlayer;
         game.game_seed = game_seed;
-        
+        game.turn_timeout_secs = turn_timeout_secs;
+        game.pending_validation = [false, false];
+        game.pending_shot_shooter = -1;
+        game.starter = -1;
+
         // The rest of the fields are zero-initialized by Anchor's `init` constraint,
         // which serves as a valid default state.
 
@@ -109,18 +136,30 @@ use arcium_anchor::prelude::*;
      }
  
          /**
-     * @description Submits a player's encrypted board layout.
+     * @description Submits a player's encrypted board layout and queues the
+     * confidential `validate_board` circuit to enforce fleet legality before
+     * the board is accepted. Also commits the player's secret seed for the
+     * `coin_flip` common coin that later decides who takes the first turn;
+     * committing it here, before either player's board is revealed, means
+     * neither player can bias the coin with knowledge of the other's seed.
      *
      * @validation
      * - Fails if the game is not in the `BoardSetup` state.
      * - Fails if the player has already submitted their board.
-     * - Transitions the game state to `P1Turn` if both boards are submitted.
+     * - Fails if a `validate_board` computation from an earlier submission
+     *   is still in flight for this player.
+     * - `boards_submitted[player_index]` only flips to `true` once
+     *   `validate_board_callback` confirms the circuit returned `1`; the game
+     *   only transitions to `P1Turn`/`P2Turn` once both boards are validated
+     *   and `flip_first_turn` has resolved the coin flip.
      */
     pub fn submit_board(
         ctx: Context<SubmitBoard>,
+        computation_offset: u64,
         encrypted_rows: [[u8; 32]; 8],
         public_key: [u8; 32],
         nonce: [u8; 16],
+        seed_ctxt: [u8; 32],
     ) -> Result<()> {
         let game = &mut ctx.accounts.game;
         let player = &ctx.accounts.player;
@@ -140,33 +179,54 @@ use arcium_anchor::prelude::*;
             return Err(GameError::PlayerNotInGame.into());
         };
 
-        // Validate that this player has not already submitted their board.
+        // Validate that this player has not already submitted their board,
+        // and that an earlier submission isn't still mid-validation — a
+        // resubmission that races the in-flight `validate_board` computation
+        // could validate one board while the game plays with another.
         require!(!game.boards_submitted[player_index], GameError::BoardAlreadySubmitted);
+        require!(!game.pending_validation[player_index], GameError::InvalidGameState);
 
-        // Store the encrypted board data, ephemeral public key, and nonce in the game account.
+        // Store the encrypted board data, ephemeral public key, nonce, and
+        // coin-flip seed in the game account. `boards_submitted` is
+        // intentionally left untouched until the `validate_board` circuit
+        // confirms the fleet layout is legal.
         game.board_states[player_index] = encrypted_rows;
         game.public_keys[player_index] = public_key;
         game.nonces[player_index] = nonce;
-        game.boards_submitted[player_index] = true;
+        game.seeds[player_index] = seed_ctxt;
+        game.pending_validation[player_index] = true;
 
-        msg!("Board for player {} successfully submitted.", player_index + 1);
+        msg!("Board for player {} queued for fleet-legality validation.", player_index + 1);
 
-        // If both players have now submitted their boards, the game can begin.
-        if game.boards_submitted[0] && game.boards_submitted[1] {
-            game.game_state = GameState::P1Turn;
-            msg!("Both boards submitted. Game state transitioned to P1Turn.");
+        let mut args = vec![
+            Argument::ArcisPubkey(public_key),
+            Argument::PlaintextU128(u128::from_le_bytes(nonce)),
+        ];
+        for row in encrypted_rows.iter() {
+            args.push(Argument::EncryptedU8(*row));
         }
+        args.push(Argument::PlaintextU8(player_index as u8));
+
+        queue_computation(ctx.accounts, computation_offset, args, None, vec![])?;
 
         Ok(())
     }
- 
-             /**
-     * @description Placeholder for firing a shot at the opponent's board.
-     * Now uses embedded game log in the Game account.
+
+    /**
+     * @description Fires a shot at the opponent's board, queuing the
+     * confidential `check_shot` circuit against the targeted row of their
+     * encrypted board. The shot is only recorded, and the turn only passes,
+     * once `check_shot_callback` resolves the hit/miss result.
+     *
+     * @validation
+     * - Fails if `target_row`/`target_col` are not within the 8x8 board.
+     * - Fails if the game is not awaiting a shot (`P1Turn`/`P2Turn`).
+     * - Fails if it is not the caller's turn.
+     * - Fails if the targeted square was already fired upon this game.
      */
     pub fn fire_shot(
         ctx: Context<FireShot>,
-        _computation_offset: u64,
+        computation_offset: u64,
         target_row: u8,
         target_col: u8,
     ) -> Result<()> {
@@ -175,40 +235,486 @@ use arcium_anchor::prelude::*;
 
         msg!("Player {} firing shot at ({}, {})", player.key(), target_row, target_col);
 
-        // Validate game state and player turn logic here
+        require!(target_row < 8 && target_col < 8, GameError::InvalidCoordinate);
+
         require!(
             game.game_state == GameState::P1Turn || game.game_state == GameState::P2Turn,
             GameError::InvalidGameState
         );
 
-        // Create shot record in embedded game log
+        let shooter_index: usize = if player.key() == game.players[0] {
+            0
+        } else if player.key() == game.players[1] {
+            1
+        } else {
+            return Err(GameError::PlayerNotInGame.into());
+        };
+
+        let on_turn_index: usize = if game.game_state == GameState::P1Turn { 0 } else { 1 };
+        require!(shooter_index == on_turn_index, GameError::NotYourTurn);
+
+        let bit = (target_row as u64) * 8 + target_col as u64;
+        require!(
+            game.targeted[shooter_index] & (1 << bit) == 0,
+            GameError::SquareAlreadyTargeted
+        );
+        game.targeted[shooter_index] |= 1 << bit;
+
+        let defender_index = 1 - shooter_index;
+        game.pending_shot_active = true;
+        game.pending_shot_shooter = shooter_index as i8;
+        game.pending_shot_coord = Coordinate { x: target_col, y: target_row };
+
+        let args = vec![
+            Argument::ArcisPubkey(game.public_keys[defender_index]),
+            Argument::PlaintextU128(u128::from_le_bytes(game.nonces[defender_index])),
+            Argument::EncryptedU8(game.board_states[defender_index][target_row as usize]),
+            Argument::PlaintextU8(target_col),
+        ];
+
+        queue_computation(ctx.accounts, computation_offset, args, None, vec![])?;
+
+        msg!("Shot queued for check_shot resolution.");
+
+        Ok(())
+    }
+
+    /**
+     * @description One-time setup instruction that registers the
+     * `check_shot` circuit's computation definition with the Arcium network
+     * so `fire_shot` can queue computations against it.
+     */
+    pub fn init_check_shot_comp_def(ctx: Context<InitCheckShotCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, true, None, None)?;
+        Ok(())
+    }
+
+    /**
+     * @description Callback invoked once the `check_shot` circuit queued in
+     * `fire_shot` resolves. Records the hit/miss result in `game_log`,
+     * updates the shooter's hit count, declares a winner once a player's
+     * hits reach `SHIP_CELL_COUNT`, and otherwise passes the turn.
+     *
+     * `game_log` is sized to `MAX_SHOTS_LOGGED` (each player's `targeted`
+     * bitmask caps them at 64 shots, so 128 covers the true worst case of
+     * both players exhausting their board before either wins) so every shot
+     * a real game can produce stays auditable by `audit_game`.
+     */
+    #[arcium_callback(encrypted_ix = "check_shot")]
+    pub fn check_shot_callback(
+        ctx: Context<FireShotCallback>,
+        output: ComputationOutputs<CheckShotOutput>,
+    ) -> Result<()> {
+        let game = &mut ctx.accounts.game;
+
+        let hit = match output {
+            ComputationOutputs::Success(CheckShotOutput { field_0 }) => field_0 == 1,
+            _ => return Err(GameError::InvalidGameState.into()),
+        };
+
+        require!(game.pending_shot_active, GameError::InvalidGameState);
+        let shooter_index = game.pending_shot_shooter as usize;
+        let coord = game.pending_shot_coord;
+        game.pending_shot_active = false;
+        game.pending_shot_shooter = -1;
+
         let shot = Shot {
-            player: player.key(),
-            coord: Coordinate { x: target_col, y: target_row },
-            result: HitOrMiss::Miss, // Placeholder
+            player: game.players[shooter_index],
+            coord,
+            result: if hit { HitOrMiss::Hit } else { HitOrMiss::Miss },
         };
 
-        // Add to game log if there's space
         let current_log_idx = game.log_idx as usize;
-        if current_log_idx < 64 {
+        if current_log_idx < MAX_SHOTS_LOGGED {
             game.game_log[current_log_idx] = shot;
             game.log_idx += 1;
         }
 
+        if hit {
+            game.hits[shooter_index] += 1;
+        }
         game.turn_number += 1;
-        msg!("Shot recorded in game log. Turn number: {}", game.turn_number);
-        
+
+        if game.hits[shooter_index] >= SHIP_CELL_COUNT {
+            game.game_state = if shooter_index == 0 { GameState::P1Won } else { GameState::P2Won };
+            msg!("Player {} sank the last ship and won the game.", shooter_index + 1);
+        } else {
+            game.game_state = if shooter_index == 0 { GameState::P2Turn } else { GameState::P1Turn };
+            game.turn_started_at = Clock::get()?.unix_timestamp;
+        }
+
+        msg!(
+            "Shot resolved: {}. Turn number: {}",
+            if hit { "hit" } else { "miss" },
+            game.turn_number
+        );
+
         Ok(())
     }
- 
-     
- 
-     pub fn forfeit(_ctx: Context<ForfeitGame>) -> Result<()> {
-         // TODO: Implement logic in a future step.
+
+     /**
+      * @description Allows a participant to concede an in-progress game at any time,
+      * immediately awarding the win to their opponent.
+      *
+      * @validation
+      * - Fails unless the game has a real opponent and is still in progress
+      *   (`BoardSetup`/`P1Turn`/`P2Turn`); in particular this excludes
+      *   `AwaitingPlayer`, where `players[1]` is still unset.
+      * - Fails if the signer is not one of the two players in this game.
+      */
+     pub fn forfeit(ctx: Context<ForfeitGame>) -> Result<()> {
+         let game = &mut ctx.accounts.game;
+         let player = &ctx.accounts.player;
+
+         require!(
+             game.game_state == GameState::BoardSetup
+                 || game.game_state == GameState::P1Turn
+                 || game.game_state == GameState::P2Turn,
+             GameError::InvalidGameState
+         );
+
+         let player_index = if player.key() == game.players[0] {
+             0
+         } else if player.key() == game.players[1] {
+             1
+         } else {
+             return Err(GameError::PlayerNotInGame.into());
+         };
+
+         let winner_index = 1 - player_index;
+         game.game_state = if winner_index == 0 { GameState::P1Won } else { GameState::P2Won };
+
+         msg!("Player {} forfeited. Player {} wins.", player_index + 1, winner_index + 1);
+
+         emit!(GameForfeited {
+             game: game.key(),
+             forfeiting_player: player.key(),
+             winner: game.players[winner_index],
+         });
+
+         Ok(())
+     }
+
+     /**
+      * @description Resolves an abandoned game in favor of the player who is NOT
+      * on turn, once their opponent has let the turn clock run out.
+      *
+      * @validation
+      * - Fails if the game is not awaiting a shot (`P1Turn`/`P2Turn`).
+      * - Fails if the caller is not a participant in this game.
+      * - Fails if the caller is the player currently on turn (they cannot claim
+      *   their own timeout).
+      * - Fails if `turn_timeout_secs` has not yet elapsed since `turn_started_at`.
+      */
+     pub fn claim_timeout_win(ctx: Context<ClaimTimeoutWin>) -> Result<()> {
+         let game = &mut ctx.accounts.game;
+         let claimant = &ctx.accounts.claimant;
+
+         require!(
+             game.game_state == GameState::P1Turn || game.game_state == GameState::P2Turn,
+             GameError::InvalidGameState
+         );
+
+         let on_turn_index: usize = if game.game_state == GameState::P1Turn { 0 } else { 1 };
+
+         let claimant_index = if claimant.key() == game.players[0] {
+             0
+         } else if claimant.key() == game.players[1] {
+             1
+         } else {
+             return Err(GameError::PlayerNotInGame.into());
+         };
+
+         require!(claimant_index != on_turn_index, GameError::CannotClaimOwnTurn);
+
+         let now = ctx.accounts.clock.unix_timestamp;
+         require!(
+             now - game.turn_started_at > game.turn_timeout_secs as i64,
+             GameError::TurnNotExpired
+         );
+
+         game.game_state = if on_turn_index == 0 { GameState::P2Won } else { GameState::P1Won };
+
+         msg!("Player {} claimed a timeout win over player {}.", claimant_index + 1, on_turn_index + 1);
+
+         emit!(TimeoutWinClaimed {
+             game: game.key(),
+             winner: claimant.key(),
+             loser: game.players[on_turn_index],
+         });
+
+         Ok(())
+     }
+
+     /**
+      * @description One-time setup instruction that registers the
+      * `validate_board` circuit's computation definition with the Arcium
+      * network so `submit_board` can queue computations against it.
+      */
+     pub fn init_validate_board_comp_def(ctx: Context<InitValidateBoardCompDef>) -> Result<()> {
+         init_comp_def(ctx.accounts, true, None, None)?;
+         Ok(())
+     }
+
+     /**
+      * @description Callback invoked by the Arcium network once the
+      * `validate_board` circuit queued in `submit_board` resolves. Only flips
+      * `boards_submitted[player_index]` to `true` when the circuit reports
+      * the fleet layout is legal; otherwise the submission is discarded and
+      * the player must resubmit.
+      *
+      * Both players can have a `validate_board` computation in flight at
+      * once (the normal case, since both typically submit right after
+      * joining), so which player this result belongs to is not tracked via
+      * a single shared field. Instead `submit_board` passes `player_index`
+      * into the circuit as a public argument, and the circuit echoes it
+      * back in its output so the callback can tell the two results apart.
+      */
+     #[arcium_callback(encrypted_ix = "validate_board")]
+     pub fn validate_board_callback(
+         ctx: Context<ValidateBoardCallback>,
+         output: ComputationOutputs<ValidateBoardOutput>,
+     ) -> Result<()> {
+         let game = &mut ctx.accounts.game;
+
+         let (is_legal, player_index) = match output {
+             ComputationOutputs::Success(ValidateBoardOutput { field_0, field_1 }) => {
+                 (field_0 == 1, field_1 as usize)
+             }
+             _ => return Err(GameError::InvalidGameState.into()),
+         };
+
+         require!(player_index < 2, GameError::PlayerNotInGame);
+         require!(game.pending_validation[player_index], GameError::InvalidGameState);
+         game.pending_validation[player_index] = false;
+
+         // An illegal layout is discarded rather than returned as an error:
+         // an error would revert the `pending_validation` reset above and
+         // lock the player out of resubmitting.
+         if !is_legal {
+             msg!("Board for player {} failed fleet-legality validation.", player_index + 1);
+             return Ok(());
+         }
+
+         game.boards_submitted[player_index] = true;
+         msg!("Board for player {} passed fleet-legality validation.", player_index + 1);
+
+         // Once both boards are validated, play can begin as soon as
+         // `flip_first_turn` resolves who goes first; it does not start here.
+         if game.boards_submitted[0] && game.boards_submitted[1] {
+             msg!("Both boards validated. Awaiting flip_first_turn to begin play.");
+         }
+
+         Ok(())
+     }
+
+     /**
+      * @description One-time setup instruction that registers the
+      * `coin_flip` circuit's computation definition with the Arcium network.
+      */
+     pub fn init_coin_flip_comp_def(ctx: Context<InitCoinFlipCompDef>) -> Result<()> {
+         init_comp_def(ctx.accounts, true, None, None)?;
+         Ok(())
+     }
+
+     /**
+      * @description Fairly decides which player takes the first turn by
+      * queuing the `coin_flip` circuit against both players' committed
+      * seeds. Neither player can bias the result: both seeds were committed
+      * in `submit_board`, before either side could see the other's.
+      *
+      * @validation
+      * - Fails unless both boards have already passed `validate_board`.
+      * - Fails if the game is not in the `BoardSetup` state (i.e. the coin
+      *   has already been flipped for this game).
+      * - Fails if a `coin_flip` computation has already been queued for
+      *   this game and has not yet resolved.
+      */
+     pub fn flip_first_turn(ctx: Context<FlipFirstTurn>, computation_offset: u64) -> Result<()> {
+         let game = &mut ctx.accounts.game;
+
+         require!(game.game_state == GameState::BoardSetup, GameError::InvalidGameState);
+         require!(
+             game.boards_submitted[0] && game.boards_submitted[1],
+             GameError::BoardsNotSubmitted
+         );
+         require!(!game.pending_coin_flip, GameError::InvalidGameState);
+         game.pending_coin_flip = true;
+
+         let args = vec![
+             Argument::ArcisPubkey(game.public_keys[0]),
+             Argument::PlaintextU128(u128::from_le_bytes(game.nonces[0])),
+             Argument::EncryptedU64(game.seeds[0]),
+             Argument::ArcisPubkey(game.public_keys[1]),
+             Argument::PlaintextU128(u128::from_le_bytes(game.nonces[1])),
+             Argument::EncryptedU64(game.seeds[1]),
+         ];
+
+         queue_computation(ctx.accounts, computation_offset, args, None, vec![])?;
+
+         msg!("Coin flip queued to determine the first turn.");
+
+         Ok(())
+     }
+
+     /**
+      * @description Callback invoked once the `coin_flip` circuit queued in
+      * `flip_first_turn` resolves. Stores the chosen starter and transitions
+      * the game into `P1Turn`/`P2Turn` accordingly, starting the turn clock.
+      *
+      * Guarded by `pending_coin_flip` so a stale or duplicate callback can't
+      * silently revert an in-progress or finished game back to a fresh turn.
+      */
+     #[arcium_callback(encrypted_ix = "coin_flip")]
+     pub fn coin_flip_callback(
+         ctx: Context<CoinFlipCallback>,
+         output: ComputationOutputs<CoinFlipOutput>,
+     ) -> Result<()> {
+         let game = &mut ctx.accounts.game;
+
+         let starter_index = match output {
+             ComputationOutputs::Success(CoinFlipOutput { field_0 }) => field_0 as usize,
+             _ => return Err(GameError::InvalidGameState.into()),
+         };
+
+         require!(game.pending_coin_flip, GameError::InvalidGameState);
+         game.pending_coin_flip = false;
+
+         game.starter = starter_index as i8;
+         game.game_state = if starter_index == 0 { GameState::P1Turn } else { GameState::P2Turn };
+         game.turn_started_at = Clock::get()?.unix_timestamp;
+
+         msg!("Coin flip resolved. Player {} takes the first turn.", starter_index + 1);
+
+         emit!(FirstTurnDecided {
+             game: game.key(),
+             starter: game.players[starter_index],
+         });
+
+         Ok(())
+     }
+
+     /**
+      * @description One-time setup instruction that registers the
+      * `audit_game` circuit's computation definition with the Arcium network.
+      */
+     pub fn init_audit_game_comp_def(ctx: Context<InitAuditGameCompDef>) -> Result<()> {
+         init_comp_def(ctx.accounts, true, None, None)?;
+         Ok(())
+     }
+
+     /**
+      * @description End-of-game integrity audit. Queues the `audit_game`
+      * circuit, which replays `game_log` against both players' encrypted
+      * boards and flags whether either player's recorded shots are
+      * inconsistent with their opponent's true board.
+      *
+      * @validation
+      * - Fails unless the game has already concluded (`P1Won`/`P2Won`).
+      * - Fails if the game has already been audited; the overturn decision
+      *   in `audit_game_callback` is made at most once per game.
+      */
+     pub fn audit_game(ctx: Context<AuditGame>, computation_offset: u64) -> Result<()> {
+         let game = &ctx.accounts.game;
+
+         require!(
+             game.game_state == GameState::P1Won || game.game_state == GameState::P2Won,
+             GameError::GameNotOver
+         );
+         require!(!game.audited, GameError::InvalidGameState);
+
+         let mut args = vec![
+             Argument::ArcisPubkey(game.public_keys[0]),
+             Argument::PlaintextU128(u128::from_le_bytes(game.nonces[0])),
+         ];
+         for row in game.board_states[0].iter() {
+             args.push(Argument::EncryptedU8(*row));
+         }
+         args.push(Argument::ArcisPubkey(game.public_keys[1]));
+         args.push(Argument::PlaintextU128(u128::from_le_bytes(game.nonces[1])));
+         for row in game.board_states[1].iter() {
+             args.push(Argument::EncryptedU8(*row));
+         }
+
+         // The public shot log, flattened to (x, y, defender, result) tuples
+         // and padded to MAX_SHOTS_LOGGED entries so the circuit can iterate
+         // unconditionally.
+         for i in 0..MAX_SHOTS_LOGGED {
+             if i < game.log_idx as usize {
+                 let shot = &game.game_log[i];
+                 let defender: u8 = if shot.player == game.players[0] { 1 } else { 0 };
+                 args.push(Argument::PlaintextU8(shot.coord.x));
+                 args.push(Argument::PlaintextU8(shot.coord.y));
+                 args.push(Argument::PlaintextU8(defender));
+                 args.push(Argument::PlaintextU8(shot.result as u8));
+             } else {
+                 args.push(Argument::PlaintextU8(0));
+                 args.push(Argument::PlaintextU8(0));
+                 args.push(Argument::PlaintextU8(0));
+                 args.push(Argument::PlaintextU8(0));
+             }
+         }
+         args.push(Argument::PlaintextU8(game.log_idx));
+
+         queue_computation(ctx.accounts, computation_offset, args, None, vec![])?;
+
+         Ok(())
+     }
+
+     /**
+      * @description Callback invoked once the `audit_game` circuit queued in
+      * `audit_game` resolves. Overturns the declared winner if the circuit
+      * found their recorded shots inconsistent with their opponent's true
+      * board, awarding the game to the honest player instead.
+      *
+      * Guarded by the one-shot `audited` flag so the overturn decision is
+      * made exactly once against the originally declared winner, even if
+      * both players' shot logs turn out to be inconsistent (in which case a
+      * later resolution would otherwise flip the winner back and forth).
+      */
+     #[arcium_callback(encrypted_ix = "audit_game")]
+     pub fn audit_game_callback(
+         ctx: Context<AuditGameCallback>,
+         output: ComputationOutputs<AuditGameOutput>,
+     ) -> Result<()> {
+         let game = &mut ctx.accounts.game;
+
+         let (p1_cheated, p2_cheated) = match output {
+             ComputationOutputs::Success(AuditGameOutput { field_0, field_1 }) => {
+                 (field_0 == 1, field_1 == 1)
+             }
+             _ => return Err(GameError::InvalidGameState.into()),
+         };
+
+         require!(!game.audited, GameError::InvalidGameState);
+         game.audited = true;
+
+         let original_winner = game.game_state;
+         if game.game_state == GameState::P1Won && p1_cheated {
+             game.game_state = GameState::P2Won;
+         } else if game.game_state == GameState::P2Won && p2_cheated {
+             game.game_state = GameState::P1Won;
+         }
+
+         msg!(
+             "Audit complete. p1_cheated={}, p2_cheated={}, final_state={:?}",
+             p1_cheated,
+             p2_cheated,
+             game.game_state
+         );
+
+         emit!(GameAudited {
+             game: game.key(),
+             original_winner,
+             final_winner: game.game_state,
+             p1_cheated,
+             p2_cheated,
+         });
+
          Ok(())
      }
  }
- 
+
  // ========================================
  // Account Context Structs
  // ========================================
@@ -236,19 +742,24 @@ pub struct InitializeGame<'info> {
      pub game: Account<'info, Game>,
  }
  
+ #[queue_computation_accounts("validate_board", player)]
  #[derive(Accounts)]
+#[instruction(computation_offset: u64)]
 pub struct SubmitBoard<'info> {
+    #[account(mut)]
     pub player: Signer<'info>,
     #[account(
-        mut, 
-        seeds = [b"game", game.game_seed.to_le_bytes().as_ref()], 
+        mut,
+        seeds = [b"game", game.game_seed.to_le_bytes().as_ref()],
         bump,
         // Ensure the signer is one of the players in the game account.
         constraint = player.key() == game.players[0] || player.key() == game.players[1]
     )]
     pub game: Account<'info, Game>,
+    pub system_program: Program<'info, System>,
 }
  
+ #[queue_computation_accounts("check_shot", payer)]
  #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
 pub struct FireShot<'info> {
@@ -258,9 +769,13 @@ pub struct FireShot<'info> {
     pub game: Account<'info, Game>,
     pub system_program: Program<'info, System>,
 }
- 
+
+ #[callback_accounts("check_shot")]
  #[derive(Accounts)]
  pub struct FireShotCallback<'info> {
+     pub arcium_program: Program<'info, Arcium>,
+     #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_SHOT))]
+     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
      #[account(mut)]
      pub payer: Signer<'info>,
      #[account(mut)]
@@ -273,13 +788,98 @@ pub struct FireShot<'info> {
      #[account(mut, seeds = [b"game", game.game_seed.to_le_bytes().as_ref()], bump)]
      pub game: Account<'info, Game>,
  }
+
+ #[derive(Accounts)]
+ pub struct ClaimTimeoutWin<'info> {
+     pub claimant: Signer<'info>,
+     #[account(mut, seeds = [b"game", game.game_seed.to_le_bytes().as_ref()], bump)]
+     pub game: Account<'info, Game>,
+     pub clock: Sysvar<'info, Clock>,
+ }
  
+ #[init_computation_definition_accounts("check_shot", payer)]
  #[derive(Accounts)]
  pub struct InitCheckShotCompDef<'info> {
      #[account(mut)]
      pub payer: Signer<'info>,
      pub system_program: Program<'info, System>,
  }
+
+ #[init_computation_definition_accounts("validate_board", payer)]
+ #[derive(Accounts)]
+ pub struct InitValidateBoardCompDef<'info> {
+     #[account(mut)]
+     pub payer: Signer<'info>,
+     pub system_program: Program<'info, System>,
+ }
+
+ #[callback_accounts("validate_board")]
+ #[derive(Accounts)]
+ pub struct ValidateBoardCallback<'info> {
+     pub arcium_program: Program<'info, Arcium>,
+     #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_VALIDATE_BOARD))]
+     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+     #[account(mut)]
+     pub game: Account<'info, Game>,
+ }
+
+ #[init_computation_definition_accounts("coin_flip", payer)]
+ #[derive(Accounts)]
+ pub struct InitCoinFlipCompDef<'info> {
+     #[account(mut)]
+     pub payer: Signer<'info>,
+     pub system_program: Program<'info, System>,
+ }
+
+ #[queue_computation_accounts("coin_flip", payer)]
+ #[derive(Accounts)]
+ #[instruction(computation_offset: u64)]
+ pub struct FlipFirstTurn<'info> {
+     #[account(mut)]
+     pub payer: Signer<'info>,
+     #[account(mut, seeds = [b"game", game.game_seed.to_le_bytes().as_ref()], bump)]
+     pub game: Account<'info, Game>,
+     pub system_program: Program<'info, System>,
+ }
+
+ #[callback_accounts("coin_flip")]
+ #[derive(Accounts)]
+ pub struct CoinFlipCallback<'info> {
+     pub arcium_program: Program<'info, Arcium>,
+     #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_COIN_FLIP))]
+     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+     #[account(mut)]
+     pub game: Account<'info, Game>,
+ }
+
+ #[init_computation_definition_accounts("audit_game", payer)]
+ #[derive(Accounts)]
+ pub struct InitAuditGameCompDef<'info> {
+     #[account(mut)]
+     pub payer: Signer<'info>,
+     pub system_program: Program<'info, System>,
+ }
+
+ #[queue_computation_accounts("audit_game", payer)]
+ #[derive(Accounts)]
+ #[instruction(computation_offset: u64)]
+ pub struct AuditGame<'info> {
+     #[account(mut)]
+     pub payer: Signer<'info>,
+     #[account(mut, seeds = [b"game", game.game_seed.to_le_bytes().as_ref()], bump)]
+     pub game: Account<'info, Game>,
+     pub system_program: Program<'info, System>,
+ }
+
+ #[callback_accounts("audit_game")]
+ #[derive(Accounts)]
+ pub struct AuditGameCallback<'info> {
+     pub arcium_program: Program<'info, Arcium>,
+     #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_AUDIT_GAME))]
+     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+     #[account(mut)]
+     pub game: Account<'info, Game>,
+ }
  
  // ========================================
  // On-Chain State Structs & Events
@@ -297,11 +897,35 @@ pub struct Game {
     pub nonces: [[u8; 16]; 2],                   // 32
     pub public_keys: [[u8; 32]; 2],              // 64
     // Public log of all shots fired. Max 64 shots for an 8x8 grid.
-    pub game_log: [Shot; 64],                   // 4480 (64 * (32 + 2 + 1 + padding))
+    pub game_log: [Shot; MAX_SHOTS_LOGGED],     // 8960 (128 * (32 + 2 + 1 + padding))
     pub log_idx: u8,                             // 1
     pub game_state: GameState,                   // 1
     pub game_seed: u64,                          // 8
     pub boards_submitted: [bool; 2],             // 2
+    pub turn_started_at: i64,                    // 8
+    pub turn_timeout_secs: u64,                  // 8
+    // Per-player flag for whether a `validate_board` computation is
+    // currently in flight for that player's submitted board.
+    pub pending_validation: [bool; 2],           // 2
+    // Per-player count of confirmed hits against their opponent's board.
+    pub hits: [u8; 2],                           // 2
+    // Per-player bitmask of cells already targeted (one bit per 8x8 cell).
+    pub targeted: [u64; 2],                      // 16
+    // Whether a `check_shot` computation is currently in flight.
+    pub pending_shot_active: bool,               // 1
+    // Index of the player who fired the in-flight shot, or -1 if none.
+    pub pending_shot_shooter: i8,                // 1
+    // Coordinate of the in-flight shot, set by `fire_shot` and logged once `check_shot_callback` resolves.
+    pub pending_shot_coord: Coordinate,          // 2
+    // Per-player encrypted coin-flip seed, committed in `submit_board`.
+    pub seeds: [[u8; 32]; 2],                    // 64
+    // Index of the player who took the first turn, or -1 if not yet decided.
+    pub starter: i8,                             // 1
+    // Whether a `coin_flip` computation is currently in flight.
+    pub pending_coin_flip: bool,                 // 1
+    // Whether `audit_game_callback` has already resolved the one-shot
+    // end-of-game overturn decision for this game.
+    pub audited: bool,                           // 1
 }
 
 impl Game {
@@ -310,11 +934,23 @@ impl Game {
         + 512                     // board_states (2 * 8 * 32)
         + 32                      // nonces
         + 64                      // public_keys
-        + (70 * 64)               // game_log (Shot is large due to alignment, ~70 bytes * 64)
+        + (70 * 128)              // game_log (Shot is large due to alignment, ~70 bytes * 128)
         + 1                       // log_idx
         + 1                       // game_state (enum repr u8)
         + 8                       // game_seed
-        + 2;                      // boards_submitted
+        + 2                       // boards_submitted
+        + 8                       // turn_started_at
+        + 8                       // turn_timeout_secs
+        + 2                       // pending_validation
+        + 2                       // hits
+        + 16                      // targeted
+        + 1                       // pending_shot_active
+        + 1                       // pending_shot_shooter
+        + 2                       // pending_shot_coord
+        + 64                      // seeds
+        + 1                       // starter
+        + 1                       // pending_coin_flip
+        + 1;                      // audited
 }
  
  // BoardData and GameLog are now embedded in the Game account
@@ -374,4 +1010,63 @@ pub enum GameError {
     InvalidPlayer,
     #[msg("The cluster is not set")]
     ClusterNotSet,
+    #[msg("The player on turn cannot claim their own timeout.")]
+    CannotClaimOwnTurn,
+    #[msg("The turn timeout has not elapsed yet.")]
+    TurnNotExpired,
+    #[msg("The submitted board does not satisfy the fleet-legality rules.")]
+    IllegalFleetLayout,
+    #[msg("The target coordinate is outside the 8x8 board.")]
+    InvalidCoordinate,
+    #[msg("The turn timeout does not fit in a signed 64-bit integer.")]
+    InvalidTurnTimeout,
+}
+
+// ========================================
+// Events
+// ========================================
+
+/**
+ * @description Emitted when a player concedes an in-progress game via `forfeit`.
+ */
+#[event]
+pub struct GameForfeited {
+    pub game: Pubkey,
+    pub forfeiting_player: Pubkey,
+    pub winner: Pubkey,
+}
+
+/**
+ * @description Emitted when a player is awarded a win via `claim_timeout_win`
+ * after their opponent's turn clock expired.
+ */
+#[event]
+pub struct TimeoutWinClaimed {
+    pub game: Pubkey,
+    pub winner: Pubkey,
+    pub loser: Pubkey,
+}
+
+/**
+ * @description Emitted when `audit_game_callback` finishes replaying the
+ * shot log against both revealed boards. `final_winner` differs from
+ * `original_winner` when the audit overturned a dishonest win.
+ */
+#[event]
+pub struct GameAudited {
+    pub game: Pubkey,
+    pub original_winner: GameState,
+    pub final_winner: GameState,
+    pub p1_cheated: bool,
+    pub p2_cheated: bool,
+}
+
+/**
+ * @description Emitted when `coin_flip_callback` resolves who takes the
+ * first turn.
+ */
+#[event]
+pub struct FirstTurnDecided {
+    pub game: Pubkey,
+    pub starter: Pubkey,
 }
\ No newline at end of file